@@ -1,8 +1,8 @@
+use intervals_general::bound_pair::BoundPair;
 use intervals_general::interval::Interval;
 use itertools::iproduct;
 use itertools::EitherOrBoth::{Both, Left, Right};
 use itertools::Itertools;
-use smallvec::smallvec;
 use std::iter::once;
 
 const DEFAULT_CAPACITY: usize = 8;
@@ -70,6 +70,113 @@ impl<T, U> ValueOverInterval<T, U> {
     }
 }
 
+impl<T, U> ValueOverInterval<T, U>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    /// Create a new ValueOverInterval from a `std::ops::RangeBounds`
+    ///
+    /// Translates `Bound::Included`/`Bound::Excluded`/`Bound::Unbounded` on
+    /// each side of `range` into the matching `Interval` variant, so callers
+    /// can use idiomatic `a..b` syntax instead of naming `Interval` variants
+    /// by hand.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` has finite bounds that are not well-ordered (e.g.
+    /// `5..3`), mirroring `BoundPair::new`'s validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    /// use piecewise::ValueOverInterval;
+    ///
+    /// let value_over_interval = ValueOverInterval::from_range(1..10, 4);
+    /// assert_eq!(
+    ///     *value_over_interval.interval(),
+    ///     Interval::RightHalfOpen {
+    ///         bound_pair: intervals_general::bound_pair::BoundPair::new(1, 10).unwrap()
+    ///     }
+    /// );
+    /// ```
+    pub fn from_range<R: std::ops::RangeBounds<T>>(range: R, value: U) -> ValueOverInterval<T, U> {
+        ValueOverInterval {
+            interval: interval_from_range_bounds(range),
+            value,
+        }
+    }
+}
+
+/// Translate a `RangeBounds<T>` into the matching `Interval<T>` variant
+///
+/// Shared by `ValueOverInterval::from_range` and `SmallPiecewise::clear_over`
+/// so both idiomatic-range entry points agree on how bounds map to
+/// `Interval` variants.
+///
+/// # Panics
+///
+/// Panics if `range` has finite bounds that are not well-ordered (e.g.
+/// `5..3`), mirroring `BoundPair::new`'s validation.
+fn interval_from_range_bounds<T: Copy + PartialOrd, R: std::ops::RangeBounds<T>>(
+    range: R,
+) -> Interval<T> {
+    use std::ops::Bound;
+
+    match (range.start_bound(), range.end_bound()) {
+        (Bound::Unbounded, Bound::Unbounded) => Interval::Unbounded,
+        (Bound::Unbounded, Bound::Included(&right)) => Interval::UnboundedClosedRight { right },
+        (Bound::Unbounded, Bound::Excluded(&right)) => Interval::UnboundedOpenRight { right },
+        (Bound::Included(&left), Bound::Unbounded) => Interval::UnboundedClosedLeft { left },
+        (Bound::Excluded(&left), Bound::Unbounded) => Interval::UnboundedOpenLeft { left },
+        (Bound::Included(&left), Bound::Included(&right)) if left == right => {
+            Interval::Singleton { at: left }
+        }
+        (Bound::Included(&left), Bound::Included(&right)) => Interval::Closed {
+            bound_pair: BoundPair::new(left, right).expect("range bounds must be well-ordered"),
+        },
+        (Bound::Included(&left), Bound::Excluded(&right)) => Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(left, right).expect("range bounds must be well-ordered"),
+        },
+        (Bound::Excluded(&left), Bound::Included(&right)) => Interval::LeftHalfOpen {
+            bound_pair: BoundPair::new(left, right).expect("range bounds must be well-ordered"),
+        },
+        (Bound::Excluded(&left), Bound::Excluded(&right)) => Interval::Open {
+            bound_pair: BoundPair::new(left, right).expect("range bounds must be well-ordered"),
+        },
+    }
+}
+
+/// `existing` with whatever `cut` covers removed, keeping each surviving
+/// piece's original value
+///
+/// Shared by `SmallPiecewiseBuilder::add_overlay`, `BigPiecewiseBuilder::add_overlay`,
+/// and `SmallPiecewise::clear_over` so the complement/intersect walk that
+/// deconflicts against a cut region is only written once.
+fn deconflict<T, U, C>(existing: &[ValueOverInterval<T, U>], cut: &Interval<T>) -> C
+where
+    T: Copy,
+    T: PartialOrd,
+    U: Copy,
+    C: std::iter::FromIterator<ValueOverInterval<T, U>>,
+{
+    iproduct!(existing, cut.complement())
+        .filter_map(|(self_voi, complement_interval)| {
+            let intersection = self_voi.interval().intersect(&complement_interval);
+            if let Interval::Empty = intersection {
+                // Empty interval ValueOverInterval are not meaningful, discard
+                None
+            } else {
+                Some(ValueOverInterval {
+                    interval: intersection,
+                    value: *self_voi.value(),
+                })
+            }
+        })
+        .collect()
+}
+
 type ValueOverIntervalOptionalTuple<T, U, V> = (
     Option<ValueOverInterval<T, U>>,
     Option<ValueOverInterval<T, V>>,
@@ -192,6 +299,125 @@ where
             .find(|voi| voi.interval().contains(&Interval::Singleton { at }))
             .map(|voi| voi.value())
     }
+
+    /// Iterate over the segments the function is defined over
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    /// use piecewise::SmallPiecewiseBuilder;
+    /// use piecewise::ValueOverInterval;
+    ///
+    /// let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+    /// let small_piecewise = builder
+    ///     .add_overlay(ValueOverInterval::new(
+    ///         Interval::UnboundedOpenRight { right: 200 },
+    ///         1.0,
+    ///     ))
+    ///     .build();
+    ///
+    /// assert_eq!(small_piecewise.segments().count(), 1);
+    /// ```
+    pub fn segments(&self) -> impl Iterator<Item = &ValueOverInterval<T, U>> {
+        self.values_over_intervals.iter()
+    }
+
+    /// Iterate over the maximal intervals of the domain the function is not
+    /// defined over
+    ///
+    /// Computed by successively intersecting each stored segment's
+    /// complement, mirroring the way rustc's `IntervalSet::iter_intervals`
+    /// reconstructs the complement of what is stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    /// use piecewise::SmallPiecewiseBuilder;
+    /// use piecewise::ValueOverInterval;
+    ///
+    /// let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+    /// let small_piecewise = builder
+    ///     .add_overlay(ValueOverInterval::new(
+    ///         Interval::UnboundedOpenRight { right: 200 },
+    ///         1.0,
+    ///     ))
+    ///     .build();
+    ///
+    /// let gaps: Vec<_> = small_piecewise.gaps().collect();
+    /// assert_eq!(gaps, vec![Interval::UnboundedClosedLeft { left: 200 }]);
+    /// ```
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> {
+        self.values_over_intervals
+            .iter()
+            .fold(vec![Interval::Unbounded], |undefined, voi| {
+                undefined
+                    .into_iter()
+                    .flat_map(|region| {
+                        voi.interval()
+                            .complement()
+                            .map(move |piece| region.intersect(&piece))
+                    })
+                    .filter(|region| !matches!(region, Interval::Empty))
+                    .collect()
+            })
+            .into_iter()
+    }
+}
+
+impl<T, U> SmallPiecewise<T, U>
+where
+    T: Copy,
+    T: PartialOrd,
+    U: Copy,
+{
+    /// Overlay `value` over `range`, deconflicting with the existing
+    /// definition exactly as `SmallPiecewiseBuilder::add_overlay` does
+    /// (newest addition wins).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use piecewise::SmallPiecewise;
+    ///
+    /// let mut small_piecewise: SmallPiecewise<u32, f32> = SmallPiecewise::default();
+    /// small_piecewise.set_over(.., 1.0);
+    /// small_piecewise.set_over(230.., 2.0);
+    ///
+    /// assert_eq!(small_piecewise.value_at(1), Some(&1.0));
+    /// assert_eq!(small_piecewise.value_at(230), Some(&2.0));
+    /// ```
+    pub fn set_over<R: std::ops::RangeBounds<T>>(&mut self, range: R, value: U) {
+        let builder = SmallPiecewiseBuilder {
+            values_over_intervals: std::mem::take(&mut self.values_over_intervals),
+        };
+        self.values_over_intervals = builder
+            .add_overlay(ValueOverInterval::from_range(range, value))
+            .values_over_intervals;
+    }
+
+    /// Punch a hole in the existing definition over `range`, leaving the
+    /// function undefined there, reusing the complement/intersect logic in
+    /// `add_overlay`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use piecewise::SmallPiecewise;
+    ///
+    /// let mut small_piecewise: SmallPiecewise<u32, f32> = SmallPiecewise::default();
+    /// small_piecewise.set_over(.., 1.0);
+    /// small_piecewise.clear_over(200..230);
+    ///
+    /// assert_eq!(small_piecewise.value_at(1), Some(&1.0));
+    /// assert_eq!(small_piecewise.value_at(210), None);
+    /// assert_eq!(small_piecewise.value_at(230), Some(&1.0));
+    /// ```
+    pub fn clear_over<R: std::ops::RangeBounds<T>>(&mut self, range: R) {
+        let cleared_interval = interval_from_range_bounds(range);
+        self.values_over_intervals = deconflict(&self.values_over_intervals, &cleared_interval);
+    }
 }
 
 impl<T, U> std::fmt::Display for SmallPiecewise<T, U>
@@ -220,65 +446,67 @@ where
     }
 }
 
-/// Multiply two SmallPiecewise point-wise across Domain
-///
-/// For every point defined in both Piecewise functions, multiply the values
-/// and form an output interval accordingly.  For regions of the domain having
-/// only one of two SmallPiecewise defined, the output is undefined.
-///
-/// # Examples
-///
-/// ```
-/// use intervals_general::interval::Interval;
-/// use piecewise::SmallPiecewiseBuilder;
-/// use piecewise::ValueOverInterval;
-///
-/// let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
-/// let piecewise_1 = builder
-///     .add_overlay(ValueOverInterval::new(
-///         Interval::UnboundedClosedLeft { left: 230 },
-///         2.0,
-///     ))
-///     .add_overlay(ValueOverInterval::new(
-///         Interval::UnboundedOpenRight { right: 200 },
-///         1.0,
-///     ))
-///     .build();
-///
-/// let builder = SmallPiecewiseBuilder::new();
-/// let piecewise_2 = builder
-///     .add_overlay(ValueOverInterval::new(
-///         Interval::UnboundedClosedLeft { left: 180 },
-///         -10.0,
-///     ))
-///     .build();
-///
-/// let result = piecewise_1 * piecewise_2;
-///
-/// assert_eq!(result.value_at(1), None);
-/// assert_eq!(result.value_at(190), Some(&-10.0));
-/// assert_eq!(result.value_at(200), None);
-/// assert_eq!(result.value_at(230), Some(&-20.0));
-/// ```
-impl<T, U, V> std::ops::Mul<SmallPiecewise<T, V>> for SmallPiecewise<T, U>
+impl<T, U> SmallPiecewise<T, U>
 where
     T: Copy,
     T: PartialOrd,
     U: Copy,
-    U: std::ops::Mul<V>,
-    V: Copy,
-    <U as std::ops::Mul<V>>::Output: Copy + Clone,
-    SmallPiecewise<T, <U as std::ops::Mul<V>>::Output>:
-        std::iter::FromIterator<ValueOverInterval<T, U>>,
 {
-    type Output = SmallPiecewise<T, <U as std::ops::Mul<V>>::Output>;
-
-    fn mul(self, rhs: SmallPiecewise<T, V>) -> Self::Output {
+    /// Combine two SmallPiecewise point-wise across Domain with `f`
+    ///
+    /// For every point defined in both Piecewise functions, intersects the
+    /// overlapping segments and applies `f` to the two values. For regions
+    /// of the domain having only one of the two SmallPiecewise defined, the
+    /// output is undefined. This is the engine every pointwise binary
+    /// operator (`Mul`, `Add`, `Sub`, `min`, `max`) is built on top of,
+    /// mirroring the way pubgrub's range type derives every set operator
+    /// from one intersection-driven traversal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    /// use piecewise::SmallPiecewiseBuilder;
+    /// use piecewise::ValueOverInterval;
+    ///
+    /// let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+    /// let piecewise_1 = builder
+    ///     .add_overlay(ValueOverInterval::new(
+    ///         Interval::UnboundedClosedLeft { left: 230 },
+    ///         2.0,
+    ///     ))
+    ///     .add_overlay(ValueOverInterval::new(
+    ///         Interval::UnboundedOpenRight { right: 200 },
+    ///         1.0,
+    ///     ))
+    ///     .build();
+    ///
+    /// let builder = SmallPiecewiseBuilder::new();
+    /// let piecewise_2 = builder
+    ///     .add_overlay(ValueOverInterval::new(
+    ///         Interval::UnboundedClosedLeft { left: 180 },
+    ///         -10.0,
+    ///     ))
+    ///     .build();
+    ///
+    /// let result = piecewise_1.zip_with(piecewise_2, |a, b| a * b);
+    ///
+    /// assert_eq!(result.value_at(1), None);
+    /// assert_eq!(result.value_at(190), Some(&-10.0));
+    /// assert_eq!(result.value_at(200), None);
+    /// assert_eq!(result.value_at(230), Some(&-20.0));
+    /// ```
+    pub fn zip_with<V, W, F>(self, other: SmallPiecewise<T, V>, f: F) -> SmallPiecewise<T, W>
+    where
+        V: Copy,
+        F: Fn(&U, &V) -> W,
+        SmallPiecewise<T, W>: std::iter::FromIterator<ValueOverInterval<T, W>>,
+    {
         let mut prior_intervals: ValueOverIntervalOptionalTuple<T, U, V> = (None, None);
 
         self.values_over_intervals
             .iter()
-            .merge_join_by(rhs.values_over_intervals.iter(), |a, b| {
+            .merge_join_by(other.values_over_intervals.iter(), |a, b| {
                 if let Some(cmp) = a.interval.right_partial_cmp(&b.interval) {
                     cmp
                 } else {
@@ -290,7 +518,7 @@ where
                     let retval = if let (.., Some(ref right)) = &prior_intervals {
                         once(Some(ValueOverInterval::new(
                             new_left.interval.intersect(&right.interval),
-                            new_left.value * right.value,
+                            f(&new_left.value, &right.value),
                         )))
                         .chain(once(None))
                     } else {
@@ -303,7 +531,7 @@ where
                     let retval = if let (Some(ref left), ..) = &prior_intervals {
                         once(Some(ValueOverInterval::new(
                             left.interval.intersect(&new_right.interval),
-                            left.value * new_right.value,
+                            f(&left.value, &new_right.value),
                         )))
                         .chain(once(None))
                     } else {
@@ -316,7 +544,7 @@ where
                     let new_right_induced = if let (Some(ref left), ..) = &prior_intervals {
                         Some(ValueOverInterval::new(
                             left.interval.intersect(&new_right.interval),
-                            left.value * new_right.value,
+                            f(&left.value, &new_right.value),
                         ))
                     } else {
                         None
@@ -324,7 +552,7 @@ where
                     let new_left_induced = if let (.., Some(ref right)) = &prior_intervals {
                         Some(ValueOverInterval::new(
                             new_left.interval.intersect(&right.interval),
-                            new_left.value * right.value,
+                            f(&new_left.value, &right.value),
                         ))
                     } else {
                         None
@@ -339,7 +567,7 @@ where
                     };
                     let retval = first.chain(once(Some(ValueOverInterval::new(
                         new_left.interval.intersect(&new_right.interval),
-                        new_left.value * new_right.value,
+                        f(&new_left.value, &new_right.value),
                     ))));
                     prior_intervals = (Some(*new_left), Some(*new_right));
                     retval
@@ -348,27 +576,280 @@ where
             .filter_map(|x| x)
             .collect()
     }
+
+    /// Pointwise minimum of two SmallPiecewise, built on `zip_with`
+    ///
+    /// Regions of the domain having only one of the two SmallPiecewise
+    /// defined remain undefined, exactly as `zip_with` behaves.
+    pub fn min(self, other: SmallPiecewise<T, U>) -> SmallPiecewise<T, U>
+    where
+        U: PartialOrd,
+    {
+        self.zip_with(other, |a, b| if a <= b { *a } else { *b })
+    }
+
+    /// Pointwise maximum of two SmallPiecewise, built on `zip_with`
+    ///
+    /// Regions of the domain having only one of the two SmallPiecewise
+    /// defined remain undefined, exactly as `zip_with` behaves.
+    pub fn max(self, other: SmallPiecewise<T, U>) -> SmallPiecewise<T, U>
+    where
+        U: PartialOrd,
+    {
+        self.zip_with(other, |a, b| if a >= b { *a } else { *b })
+    }
 }
 
-#[derive(Default)]
-pub struct SmallPiecewiseBuilder<T, U>
+/// Multiply two SmallPiecewise point-wise across Domain
+///
+/// For every point defined in both Piecewise functions, multiply the values
+/// and form an output interval accordingly.  For regions of the domain having
+/// only one of two SmallPiecewise defined, the output is undefined.
+///
+/// # Examples
+///
+/// ```
+/// use intervals_general::interval::Interval;
+/// use piecewise::SmallPiecewiseBuilder;
+/// use piecewise::ValueOverInterval;
+///
+/// let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+/// let piecewise_1 = builder
+///     .add_overlay(ValueOverInterval::new(
+///         Interval::UnboundedClosedLeft { left: 230 },
+///         2.0,
+///     ))
+///     .add_overlay(ValueOverInterval::new(
+///         Interval::UnboundedOpenRight { right: 200 },
+///         1.0,
+///     ))
+///     .build();
+///
+/// let builder = SmallPiecewiseBuilder::new();
+/// let piecewise_2 = builder
+///     .add_overlay(ValueOverInterval::new(
+///         Interval::UnboundedClosedLeft { left: 180 },
+///         -10.0,
+///     ))
+///     .build();
+///
+/// let result = piecewise_1 * piecewise_2;
+///
+/// assert_eq!(result.value_at(1), None);
+/// assert_eq!(result.value_at(190), Some(&-10.0));
+/// assert_eq!(result.value_at(200), None);
+/// assert_eq!(result.value_at(230), Some(&-20.0));
+/// ```
+impl<T, U, V> std::ops::Mul<SmallPiecewise<T, V>> for SmallPiecewise<T, U>
 where
     T: Copy,
     T: PartialOrd,
+    U: Copy,
+    U: std::ops::Mul<V>,
+    V: Copy,
+    <U as std::ops::Mul<V>>::Output: Copy,
 {
-    values_over_intervals: smallvec::SmallVec<[ValueOverInterval<T, U>; DEFAULT_CAPACITY]>,
-}
+    type Output = SmallPiecewise<T, <U as std::ops::Mul<V>>::Output>;
 
-impl<T, U> SmallPiecewiseBuilder<T, U>
-where
-    T: std::cmp::PartialOrd,
-    T: std::marker::Copy,
-    U: std::marker::Copy,
-{
-    pub fn new() -> SmallPiecewiseBuilder<T, U> {
-        SmallPiecewiseBuilder {
-            values_over_intervals: smallvec::SmallVec::new(),
-        }
+    fn mul(self, rhs: SmallPiecewise<T, V>) -> Self::Output {
+        self.zip_with(rhs, |a, b| *a * *b)
+    }
+}
+
+/// Add two SmallPiecewise point-wise across Domain
+///
+/// For every point defined in both Piecewise functions, add the values and
+/// form an output interval accordingly. For regions of the domain having
+/// only one of two SmallPiecewise defined, the output is undefined, exactly
+/// as `Mul` behaves.
+///
+/// # Examples
+///
+/// ```
+/// use intervals_general::interval::Interval;
+/// use piecewise::SmallPiecewiseBuilder;
+/// use piecewise::ValueOverInterval;
+///
+/// let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+/// let piecewise_1 = builder
+///     .add_overlay(ValueOverInterval::new(
+///         Interval::UnboundedClosedLeft { left: 230 },
+///         2.0,
+///     ))
+///     .build();
+///
+/// let builder = SmallPiecewiseBuilder::new();
+/// let piecewise_2 = builder
+///     .add_overlay(ValueOverInterval::new(
+///         Interval::UnboundedClosedLeft { left: 180 },
+///         3.0,
+///     ))
+///     .build();
+///
+/// let result = piecewise_1 + piecewise_2;
+///
+/// assert_eq!(result.value_at(190), None);
+/// assert_eq!(result.value_at(230), Some(&5.0));
+/// ```
+impl<T, U, V> std::ops::Add<SmallPiecewise<T, V>> for SmallPiecewise<T, U>
+where
+    T: Copy,
+    T: PartialOrd,
+    U: Copy,
+    U: std::ops::Add<V>,
+    V: Copy,
+    <U as std::ops::Add<V>>::Output: Copy,
+{
+    type Output = SmallPiecewise<T, <U as std::ops::Add<V>>::Output>;
+
+    fn add(self, rhs: SmallPiecewise<T, V>) -> Self::Output {
+        self.zip_with(rhs, |a, b| *a + *b)
+    }
+}
+
+/// Subtract two SmallPiecewise point-wise across Domain
+///
+/// For every point defined in both Piecewise functions, subtract the rhs
+/// value from the lhs value and form an output interval accordingly. For
+/// regions of the domain having only one of two SmallPiecewise defined, the
+/// output is undefined, exactly as `Mul` behaves.
+///
+/// # Examples
+///
+/// ```
+/// use intervals_general::interval::Interval;
+/// use piecewise::SmallPiecewiseBuilder;
+/// use piecewise::ValueOverInterval;
+///
+/// let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+/// let piecewise_1 = builder
+///     .add_overlay(ValueOverInterval::new(
+///         Interval::UnboundedClosedLeft { left: 230 },
+///         2.0,
+///     ))
+///     .build();
+///
+/// let builder = SmallPiecewiseBuilder::new();
+/// let piecewise_2 = builder
+///     .add_overlay(ValueOverInterval::new(
+///         Interval::UnboundedClosedLeft { left: 180 },
+///         3.0,
+///     ))
+///     .build();
+///
+/// let result = piecewise_1 - piecewise_2;
+///
+/// assert_eq!(result.value_at(190), None);
+/// assert_eq!(result.value_at(230), Some(&-1.0));
+/// ```
+impl<T, U, V> std::ops::Sub<SmallPiecewise<T, V>> for SmallPiecewise<T, U>
+where
+    T: Copy,
+    T: PartialOrd,
+    U: Copy,
+    U: std::ops::Sub<V>,
+    V: Copy,
+    <U as std::ops::Sub<V>>::Output: Copy,
+{
+    type Output = SmallPiecewise<T, <U as std::ops::Sub<V>>::Output>;
+
+    fn sub(self, rhs: SmallPiecewise<T, V>) -> Self::Output {
+        self.zip_with(rhs, |a, b| *a - *b)
+    }
+}
+
+/// Which operand wins where `SmallPiecewise::overlay_with` finds both
+/// sides defined over the same region of the domain
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl<T, U> SmallPiecewise<T, U>
+where
+    T: Copy,
+    T: PartialOrd,
+    U: Copy,
+{
+    /// Splice two SmallPiecewise into one, filling undefined regions
+    /// instead of dropping them
+    ///
+    /// Wherever only one of `self`/`other` is defined, that operand's value
+    /// is kept. Wherever both are defined, `prefer` picks which one wins.
+    /// This is the complement of `zip_with`'s semantics (and thus of `Mul`,
+    /// `Add`, etc): instead of leaving single-sided regions undefined, it
+    /// gives users a total function over the combined domain, analogous to
+    /// the union operation in pubgrub's `Range` set algebra.
+    ///
+    /// Implemented by overlaying `prefer`'s segments on top of the other
+    /// operand's, reusing `SmallPiecewiseBuilder::add_overlay`'s
+    /// newest-addition-wins deconfliction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    /// use piecewise::Side;
+    /// use piecewise::SmallPiecewiseBuilder;
+    /// use piecewise::ValueOverInterval;
+    ///
+    /// let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+    /// let piecewise_1 = builder
+    ///     .add_overlay(ValueOverInterval::new(
+    ///         Interval::UnboundedClosedLeft { left: 230 },
+    ///         2.0,
+    ///     ))
+    ///     .build();
+    ///
+    /// let builder = SmallPiecewiseBuilder::new();
+    /// let piecewise_2 = builder
+    ///     .add_overlay(ValueOverInterval::new(
+    ///         Interval::UnboundedOpenRight { right: 200 },
+    ///         1.0,
+    ///     ))
+    ///     .build();
+    ///
+    /// let result = piecewise_1.overlay_with(piecewise_2, Side::Left);
+    ///
+    /// assert_eq!(result.value_at(1), Some(&1.0));
+    /// assert_eq!(result.value_at(215), None);
+    /// assert_eq!(result.value_at(230), Some(&2.0));
+    /// ```
+    pub fn overlay_with(self, other: SmallPiecewise<T, U>, prefer: Side) -> SmallPiecewise<T, U> {
+        let (base, overlay) = match prefer {
+            Side::Left => (other, self),
+            Side::Right => (self, other),
+        };
+        base.values_over_intervals
+            .into_iter()
+            .chain(overlay.values_over_intervals)
+            .fold(SmallPiecewiseBuilder::new(), |builder, voi| {
+                builder.add_overlay(voi)
+            })
+            .build()
+    }
+}
+
+#[derive(Default)]
+pub struct SmallPiecewiseBuilder<T, U>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    values_over_intervals: smallvec::SmallVec<[ValueOverInterval<T, U>; DEFAULT_CAPACITY]>,
+}
+
+impl<T, U> SmallPiecewiseBuilder<T, U>
+where
+    T: std::cmp::PartialOrd,
+    T: std::marker::Copy,
+    U: std::marker::Copy,
+{
+    pub fn new() -> SmallPiecewiseBuilder<T, U> {
+        SmallPiecewiseBuilder {
+            values_over_intervals: smallvec::SmallVec::new(),
+        }
     }
 
     /// Consume the builder and produce a SmallPiecewise output
@@ -414,30 +895,326 @@ where
     /// assert_eq!(small_piecewise.value_at(231), Some(&2.0));
     /// ```
     pub fn add_overlay(mut self, element: ValueOverInterval<T, U>) -> Self {
-        let mut new_voi: smallvec::SmallVec<[ValueOverInterval<T, U>; DEFAULT_CAPACITY]> =
-            smallvec![];
-        for (self_voi, complement_interval) in
-            iproduct!(&self.values_over_intervals, element.interval().complement())
-        {
-            let intersection = self_voi.interval().intersect(&complement_interval);
-            if let Interval::Empty = intersection {
-                // Empty interval ValueOverInterval are not meaningful, discard
-            } else {
-                new_voi.push(ValueOverInterval {
-                    interval: intersection,
-                    value: *self_voi.value(),
-                });
+        self.values_over_intervals = deconflict(&self.values_over_intervals, element.interval());
+        self.values_over_intervals.push(element);
+        self
+    }
+}
+
+/// BigPiecewise
+///
+/// The BigPiecewise variant is for use when the number of Intervals over
+/// which the function is defined is large enough that SmallPiecewise's
+/// linear scan becomes the bottleneck. For these large entities we benefit
+/// from:
+///
+/// * Heap storage in a single `Vec` (no stack-allocated small case)
+/// * Binary search instead of linear search
+///
+/// Segments are kept sorted by right bound. Borrowing from rustc's
+/// `IntervalSet`, two adjacent segments with equal values are coalesced into
+/// one as they are added through `BigPiecewiseBuilder::add_overlay`, so
+/// repeated overlays do not fragment storage unboundedly. Adjacent segments
+/// with different values stay separate, and conversion from a SmallPiecewise
+/// does not coalesce.
+#[derive(Clone, Debug, Default)]
+pub struct BigPiecewise<T, U> {
+    values_over_intervals: Vec<ValueOverInterval<T, U>>,
+}
+
+impl<T, U> BigPiecewise<T, U>
+where
+    T: std::cmp::PartialOrd,
+    T: std::marker::Copy,
+{
+    /// Retrieves the value of the piecewise function at a specific point
+    ///
+    /// If the Domain does not contain the value specified by at: - Optional
+    /// returns None
+    ///
+    /// # Runtime
+    ///
+    /// Segments are sorted by right bound, so we binary search for the
+    /// first segment whose right bound is not less than `at` - runtime is
+    /// O(log Segment count)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    /// use piecewise::BigPiecewiseBuilder;
+    /// use piecewise::ValueOverInterval;
+    ///
+    /// let builder: BigPiecewiseBuilder<u32, f32> = BigPiecewiseBuilder::new();
+    /// let big_piecewise = builder
+    ///     .add_overlay(ValueOverInterval::new(
+    ///         Interval::UnboundedClosedLeft { left: 230 },
+    ///         2.0,
+    ///     ))
+    ///     .add_overlay(ValueOverInterval::new(
+    ///         Interval::UnboundedOpenRight { right: 200 },
+    ///         1.0,
+    ///     ))
+    ///     .build();
+    ///
+    /// assert_eq!(big_piecewise.value_at(1), Some(&1.0));
+    /// assert_eq!(big_piecewise.value_at(200), None);
+    /// assert_eq!(big_piecewise.value_at(230), Some(&2.0));
+    /// ```
+    pub fn value_at(&self, at: T) -> Option<&U> {
+        let at_interval = Interval::Singleton { at };
+        let idx = self.values_over_intervals.partition_point(|voi| {
+            matches!(
+                voi.interval().right_partial_cmp(&at_interval),
+                Some(std::cmp::Ordering::Less)
+            )
+        });
+        self.values_over_intervals
+            .get(idx)
+            .filter(|voi| voi.interval().contains(&at_interval))
+            .map(|voi| voi.value())
+    }
+}
+
+impl<T, U> std::fmt::Display for BigPiecewise<T, U>
+where
+    T: std::fmt::Debug,
+    U: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut output = String::new();
+        for i in &self.values_over_intervals {
+            output.push_str(&format!("{}{: >7?}\n", i.interval(), i.value()));
+        }
+        write!(f, "{}", output)
+    }
+}
+
+/// Convert a SmallPiecewise into a BigPiecewise, for users who outgrow the
+/// stack-backed form
+///
+/// Segments are re-sorted by right bound to satisfy BigPiecewise's storage
+/// invariant. No coalescing is performed here, since this conversion is
+/// available without a `U: PartialEq` bound.
+impl<T, U> From<SmallPiecewise<T, U>> for BigPiecewise<T, U>
+where
+    T: std::cmp::PartialOrd,
+    T: std::marker::Copy,
+{
+    fn from(small: SmallPiecewise<T, U>) -> Self {
+        let mut values_over_intervals: Vec<_> = small.values_over_intervals.into_vec();
+        values_over_intervals.sort_by(|a, b| {
+            a.interval
+                .right_partial_cmp(&b.interval)
+                .unwrap_or(std::cmp::Ordering::Less)
+        });
+        BigPiecewise {
+            values_over_intervals,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct BigPiecewiseBuilder<T, U>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    values_over_intervals: Vec<ValueOverInterval<T, U>>,
+}
+
+impl<T, U> BigPiecewiseBuilder<T, U>
+where
+    T: std::cmp::PartialOrd,
+    T: std::marker::Copy,
+    U: std::marker::Copy,
+{
+    pub fn new() -> BigPiecewiseBuilder<T, U> {
+        BigPiecewiseBuilder {
+            values_over_intervals: Vec::new(),
+        }
+    }
+
+    /// Consume the builder and produce a BigPiecewise output
+    pub fn build(self) -> BigPiecewise<T, U> {
+        BigPiecewise {
+            values_over_intervals: self.values_over_intervals,
+        }
+    }
+}
+
+/// One edge of an `Interval`, in a form we can compare and rebuild from
+///
+/// `intervals_general` does not expose its own bound type, so this mirrors
+/// it just enough to detect and merge gap-free adjacent segments.
+enum Edge<T> {
+    Unbounded,
+    Open(T),
+    Closed(T),
+}
+
+/// The left edge of `interval`, or `None` for `Interval::Empty`
+fn left_edge<T: Copy + PartialOrd>(interval: &Interval<T>) -> Option<Edge<T>> {
+    match interval {
+        Interval::Closed { bound_pair } => Some(Edge::Closed(*bound_pair.left())),
+        Interval::Open { bound_pair } => Some(Edge::Open(*bound_pair.left())),
+        Interval::LeftHalfOpen { bound_pair } => Some(Edge::Open(*bound_pair.left())),
+        Interval::RightHalfOpen { bound_pair } => Some(Edge::Closed(*bound_pair.left())),
+        Interval::UnboundedClosedRight { .. } => Some(Edge::Unbounded),
+        Interval::UnboundedOpenRight { .. } => Some(Edge::Unbounded),
+        Interval::UnboundedClosedLeft { left } => Some(Edge::Closed(*left)),
+        Interval::UnboundedOpenLeft { left } => Some(Edge::Open(*left)),
+        Interval::Singleton { at } => Some(Edge::Closed(*at)),
+        Interval::Unbounded => Some(Edge::Unbounded),
+        Interval::Empty => None,
+    }
+}
+
+/// The right edge of `interval`, or `None` for `Interval::Empty`
+fn right_edge<T: Copy + PartialOrd>(interval: &Interval<T>) -> Option<Edge<T>> {
+    match interval {
+        Interval::Closed { bound_pair } => Some(Edge::Closed(*bound_pair.right())),
+        Interval::Open { bound_pair } => Some(Edge::Open(*bound_pair.right())),
+        Interval::LeftHalfOpen { bound_pair } => Some(Edge::Closed(*bound_pair.right())),
+        Interval::RightHalfOpen { bound_pair } => Some(Edge::Open(*bound_pair.right())),
+        Interval::UnboundedClosedRight { right } => Some(Edge::Closed(*right)),
+        Interval::UnboundedOpenRight { right } => Some(Edge::Open(*right)),
+        Interval::UnboundedClosedLeft { .. } => Some(Edge::Unbounded),
+        Interval::UnboundedOpenLeft { .. } => Some(Edge::Unbounded),
+        Interval::Singleton { at } => Some(Edge::Closed(*at)),
+        Interval::Unbounded => Some(Edge::Unbounded),
+        Interval::Empty => None,
+    }
+}
+
+/// Rebuild the `Interval` spanning from `left` to `right`
+fn interval_from_edges<T: Copy + PartialOrd>(left: Edge<T>, right: Edge<T>) -> Interval<T> {
+    match (left, right) {
+        (Edge::Unbounded, Edge::Unbounded) => Interval::Unbounded,
+        (Edge::Unbounded, Edge::Closed(r)) => Interval::UnboundedClosedRight { right: r },
+        (Edge::Unbounded, Edge::Open(r)) => Interval::UnboundedOpenRight { right: r },
+        (Edge::Closed(l), Edge::Unbounded) => Interval::UnboundedClosedLeft { left: l },
+        (Edge::Open(l), Edge::Unbounded) => Interval::UnboundedOpenLeft { left: l },
+        (Edge::Closed(l), Edge::Closed(r)) if l == r => Interval::Singleton { at: l },
+        (Edge::Closed(l), Edge::Closed(r)) => Interval::Closed {
+            bound_pair: BoundPair::new(l, r).expect("adjacent segments have well-ordered bounds"),
+        },
+        (Edge::Open(l), Edge::Open(r)) => Interval::Open {
+            bound_pair: BoundPair::new(l, r).expect("adjacent segments have well-ordered bounds"),
+        },
+        (Edge::Open(l), Edge::Closed(r)) => Interval::LeftHalfOpen {
+            bound_pair: BoundPair::new(l, r).expect("adjacent segments have well-ordered bounds"),
+        },
+        (Edge::Closed(l), Edge::Open(r)) => Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(l, r).expect("adjacent segments have well-ordered bounds"),
+        },
+    }
+}
+
+/// If `earlier` and `later` (in right-bound sorted order) share a boundary
+/// with no gap between them, the merged `Interval` spanning both; else
+/// `None`
+///
+/// Two segments are gap-free adjacent when their shared boundary value is
+/// covered by at least one side (e.g. `..5]` and `(5..` touch at `5` with
+/// no gap, but `..5)` and `(5..` both exclude `5`, leaving it undefined).
+fn try_merge_adjacent<T: Copy + PartialOrd>(
+    earlier: &Interval<T>,
+    later: &Interval<T>,
+) -> Option<Interval<T>> {
+    let gap_free = match (right_edge(earlier)?, left_edge(later)?) {
+        (Edge::Closed(r), Edge::Closed(l)) => r == l,
+        (Edge::Closed(r), Edge::Open(l)) => r == l,
+        (Edge::Open(r), Edge::Closed(l)) => r == l,
+        (Edge::Open(_), Edge::Open(_)) => false,
+        (Edge::Unbounded, _) | (_, Edge::Unbounded) => false,
+    };
+    if gap_free {
+        Some(interval_from_edges(left_edge(earlier)?, right_edge(later)?))
+    } else {
+        None
+    }
+}
+
+impl<T, U> BigPiecewiseBuilder<T, U>
+where
+    T: std::cmp::PartialOrd,
+    T: std::marker::Copy,
+    U: std::marker::Copy,
+    U: std::cmp::PartialEq,
+{
+    /// Add a Segment to the Builder, overlay on top of existing
+    ///
+    /// When adding a new Segment, if portions of the existing Segments
+    /// overlap in the domain, the new segment is applied and existing
+    /// segments are modified to deconflict (newest addition wins), exactly
+    /// as `SmallPiecewiseBuilder::add_overlay` does.
+    ///
+    /// The result is then kept sorted by right bound, and adjacent segments
+    /// whose shared boundary is gap-free and whose values compare equal are
+    /// coalesced into a single segment, so repeated overlays do not
+    /// fragment storage unboundedly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    /// use piecewise::BigPiecewiseBuilder;
+    /// use piecewise::ValueOverInterval;
+    ///
+    /// let builder: BigPiecewiseBuilder<u32, f32> = BigPiecewiseBuilder::new();
+    /// let big_piecewise = builder
+    ///     .add_overlay(ValueOverInterval::new(Interval::Unbounded, 5.0))
+    ///     .add_overlay(ValueOverInterval::new(
+    ///         Interval::UnboundedClosedLeft { left: 230 },
+    ///         2.0,
+    ///     ))
+    ///     .add_overlay(ValueOverInterval::new(
+    ///         Interval::UnboundedOpenRight { right: 200 },
+    ///         1.0,
+    ///     ))
+    ///     .build();
+    ///
+    /// assert_eq!(big_piecewise.value_at(1), Some(&1.0));
+    /// assert_eq!(big_piecewise.value_at(210), Some(&5.0));
+    /// assert_eq!(big_piecewise.value_at(230), Some(&2.0));
+    /// assert_eq!(big_piecewise.value_at(231), Some(&2.0));
+    /// ```
+    pub fn add_overlay(mut self, element: ValueOverInterval<T, U>) -> Self {
+        let mut new_voi: Vec<ValueOverInterval<T, U>> =
+            deconflict(&self.values_over_intervals, element.interval());
+        new_voi.push(element);
+        new_voi.sort_by(|a, b| {
+            a.interval
+                .right_partial_cmp(&b.interval)
+                .unwrap_or(std::cmp::Ordering::Less)
+        });
+
+        let mut coalesced: Vec<ValueOverInterval<T, U>> = Vec::with_capacity(new_voi.len());
+        for voi in new_voi {
+            let merged = match coalesced.last() {
+                Some(prev) if prev.value == voi.value => {
+                    try_merge_adjacent(&prev.interval, &voi.interval)
+                }
+                _ => None,
+            };
+            match merged {
+                Some(merged_interval) => coalesced.last_mut().unwrap().interval = merged_interval,
+                None => coalesced.push(voi),
             }
         }
-        self.values_over_intervals = new_voi;
-        self.values_over_intervals.push(element);
+
+        self.values_over_intervals = coalesced;
         self
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{SmallPiecewise, SmallPiecewiseBuilder, ValueOverInterval};
+    use crate::{
+        try_merge_adjacent, BigPiecewise, BigPiecewiseBuilder, Side, SmallPiecewise,
+        SmallPiecewiseBuilder, ValueOverInterval,
+    };
     use intervals_general::bound_pair::BoundPair;
     use intervals_general::interval::Interval;
 
@@ -531,4 +1308,337 @@ mod tests {
         let result = empty1 * empty2;
         assert_eq!(result.value_at(0), None);
     }
+
+    #[test]
+    fn big_piecewise_builder_add_overlay() {
+        let builder: BigPiecewiseBuilder<u32, f32> = BigPiecewiseBuilder::new();
+        let big_piecewise = builder
+            .add_overlay(ValueOverInterval::new(Interval::Unbounded, 5.0))
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedClosedLeft { left: 230 },
+                2.0,
+            ))
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedOpenRight { right: 200 },
+                1.0,
+            ))
+            .build();
+
+        println!("{}", big_piecewise);
+
+        assert_eq!(big_piecewise.value_at(1), Some(&1.0));
+        assert_eq!(big_piecewise.value_at(210), Some(&5.0));
+        assert_eq!(big_piecewise.value_at(230), Some(&2.0));
+        assert_eq!(big_piecewise.value_at(231), Some(&2.0));
+    }
+
+    #[test]
+    fn big_piecewise_builder_coalesces_equal_adjacent_segments() {
+        let builder: BigPiecewiseBuilder<u32, f32> = BigPiecewiseBuilder::new();
+        let big_piecewise = builder
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedOpenRight { right: 100 },
+                1.0,
+            ))
+            .add_overlay(ValueOverInterval::new(
+                Interval::Closed {
+                    bound_pair: BoundPair::new(100, 200).unwrap(),
+                },
+                1.0,
+            ))
+            .build();
+
+        assert_eq!(big_piecewise.value_at(50), Some(&1.0));
+        assert_eq!(big_piecewise.value_at(100), Some(&1.0));
+        assert_eq!(big_piecewise.value_at(200), Some(&1.0));
+        assert_eq!(big_piecewise.value_at(201), None);
+    }
+
+    #[test]
+    fn try_merge_adjacent_open_open_touch_leaves_a_gap() {
+        // (.., 100) and (100, ..) both exclude 100, so it remains undefined
+        // between them - not gap-free, must not merge.
+        let earlier = Interval::UnboundedOpenRight { right: 100 };
+        let later = Interval::UnboundedOpenLeft { left: 100 };
+
+        assert_eq!(try_merge_adjacent(&earlier, &later), None);
+    }
+
+    #[test]
+    fn try_merge_adjacent_closed_open_touch_merges() {
+        // (.., 100] and (100, ..) share 100 via the closed side, so the pair
+        // is gap-free and merges into the unbounded interval.
+        let earlier = Interval::UnboundedClosedRight { right: 100 };
+        let later = Interval::UnboundedOpenLeft { left: 100 };
+
+        assert_eq!(
+            try_merge_adjacent(&earlier, &later),
+            Some(Interval::Unbounded)
+        );
+    }
+
+    #[test]
+    fn try_merge_adjacent_open_closed_touch_merges() {
+        // (.., 100) and [100, ..) share 100 via the closed side, so the pair
+        // is gap-free and merges into the unbounded interval.
+        let earlier = Interval::UnboundedOpenRight { right: 100 };
+        let later = Interval::UnboundedClosedLeft { left: 100 };
+
+        assert_eq!(
+            try_merge_adjacent(&earlier, &later),
+            Some(Interval::Unbounded)
+        );
+    }
+
+    #[test]
+    fn big_piecewise_from_small_piecewise() {
+        let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+        let small_piecewise = builder
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedClosedLeft { left: 230 },
+                2.0,
+            ))
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedOpenRight { right: 200 },
+                1.0,
+            ))
+            .build();
+
+        let big_piecewise: BigPiecewise<u32, f32> = small_piecewise.into();
+
+        assert_eq!(big_piecewise.value_at(1), Some(&1.0));
+        assert_eq!(big_piecewise.value_at(200), None);
+        assert_eq!(big_piecewise.value_at(230), Some(&2.0));
+    }
+
+    #[test]
+    fn small_piecewise_zip_with_custom_combinator() {
+        let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+        let piecewise_1 = builder
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedClosedLeft { left: 230 },
+                2.0,
+            ))
+            .build();
+
+        let builder = SmallPiecewiseBuilder::new();
+        let piecewise_2 = builder
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedClosedLeft { left: 180 },
+                3.0,
+            ))
+            .build();
+
+        let result = piecewise_1.zip_with(piecewise_2, |a, b| (a - b).abs());
+
+        assert_eq!(result.value_at(190), None);
+        assert_eq!(result.value_at(230), Some(&1.0));
+    }
+
+    #[test]
+    fn small_piecewise_add() {
+        let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+        let piecewise_1 = builder
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedClosedLeft { left: 230 },
+                2.0,
+            ))
+            .build();
+
+        let builder = SmallPiecewiseBuilder::new();
+        let piecewise_2 = builder
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedClosedLeft { left: 180 },
+                3.0,
+            ))
+            .build();
+
+        let result = piecewise_1 + piecewise_2;
+
+        assert_eq!(result.value_at(190), None);
+        assert_eq!(result.value_at(230), Some(&5.0));
+    }
+
+    #[test]
+    fn small_piecewise_sub() {
+        let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+        let piecewise_1 = builder
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedClosedLeft { left: 230 },
+                2.0,
+            ))
+            .build();
+
+        let builder = SmallPiecewiseBuilder::new();
+        let piecewise_2 = builder
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedClosedLeft { left: 180 },
+                3.0,
+            ))
+            .build();
+
+        let result = piecewise_1 - piecewise_2;
+
+        assert_eq!(result.value_at(190), None);
+        assert_eq!(result.value_at(230), Some(&-1.0));
+    }
+
+    #[test]
+    fn small_piecewise_min_and_max() {
+        let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+        let piecewise_1 = builder
+            .add_overlay(ValueOverInterval::new(Interval::Unbounded, 2.0))
+            .build();
+
+        let builder = SmallPiecewiseBuilder::new();
+        let piecewise_2 = builder
+            .add_overlay(ValueOverInterval::new(Interval::Unbounded, 3.0))
+            .build();
+
+        let min_result = piecewise_1.clone().min(piecewise_2.clone());
+        let max_result = piecewise_1.max(piecewise_2);
+
+        assert_eq!(min_result.value_at(0), Some(&2.0));
+        assert_eq!(max_result.value_at(0), Some(&3.0));
+    }
+
+    #[test]
+    fn small_piecewise_overlay_with_fills_undefined_regions() {
+        let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+        let piecewise_1 = builder
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedClosedLeft { left: 230 },
+                2.0,
+            ))
+            .build();
+
+        let builder = SmallPiecewiseBuilder::new();
+        let piecewise_2 = builder
+            .add_overlay(ValueOverInterval::new(
+                Interval::UnboundedOpenRight { right: 200 },
+                1.0,
+            ))
+            .build();
+
+        let result = piecewise_1.overlay_with(piecewise_2, Side::Left);
+
+        assert_eq!(result.value_at(1), Some(&1.0));
+        assert_eq!(result.value_at(215), None);
+        assert_eq!(result.value_at(230), Some(&2.0));
+    }
+
+    #[test]
+    fn small_piecewise_overlay_with_prefers_requested_side_on_overlap() {
+        let builder: SmallPiecewiseBuilder<u32, f32> = SmallPiecewiseBuilder::new();
+        let piecewise_1 = builder
+            .add_overlay(ValueOverInterval::new(Interval::Unbounded, 2.0))
+            .build();
+
+        let builder = SmallPiecewiseBuilder::new();
+        let piecewise_2 = builder
+            .add_overlay(ValueOverInterval::new(Interval::Unbounded, 3.0))
+            .build();
+
+        let left_wins = piecewise_1.clone().overlay_with(piecewise_2.clone(), Side::Left);
+        let right_wins = piecewise_1.overlay_with(piecewise_2, Side::Right);
+
+        assert_eq!(left_wins.value_at(0), Some(&2.0));
+        assert_eq!(right_wins.value_at(0), Some(&3.0));
+    }
+
+    #[test]
+    fn value_over_interval_from_range() {
+        assert_eq!(
+            ValueOverInterval::from_range(1..10, 4),
+            ValueOverInterval::new(
+                Interval::RightHalfOpen {
+                    bound_pair: BoundPair::new(1, 10).unwrap()
+                },
+                4
+            )
+        );
+        assert_eq!(
+            ValueOverInterval::from_range(1..=10, 4),
+            ValueOverInterval::new(
+                Interval::Closed {
+                    bound_pair: BoundPair::new(1, 10).unwrap()
+                },
+                4
+            )
+        );
+        assert_eq!(
+            ValueOverInterval::<u32, _>::from_range(.., 4),
+            ValueOverInterval::new(Interval::Unbounded, 4)
+        );
+        assert_eq!(
+            ValueOverInterval::from_range(230.., 4),
+            ValueOverInterval::new(Interval::UnboundedClosedLeft { left: 230 }, 4)
+        );
+        assert_eq!(
+            ValueOverInterval::from_range(..200, 4),
+            ValueOverInterval::new(Interval::UnboundedOpenRight { right: 200 }, 4)
+        );
+        assert_eq!(
+            ValueOverInterval::from_range(5..=5, 4),
+            ValueOverInterval::new(Interval::Singleton { at: 5 }, 4)
+        );
+    }
+
+    #[test]
+    fn small_piecewise_set_over_overlays_with_idiomatic_range_syntax() {
+        let mut small_piecewise: SmallPiecewise<u32, f32> = SmallPiecewise::default();
+        small_piecewise.set_over(.., 5.0);
+        small_piecewise.set_over(230.., 2.0);
+        small_piecewise.set_over(..200, 1.0);
+
+        assert_eq!(small_piecewise.value_at(1), Some(&1.0));
+        assert_eq!(small_piecewise.value_at(210), Some(&5.0));
+        assert_eq!(small_piecewise.value_at(230), Some(&2.0));
+    }
+
+    #[test]
+    fn small_piecewise_clear_over_punches_a_hole_in_the_definition() {
+        let mut small_piecewise: SmallPiecewise<u32, f32> = SmallPiecewise::default();
+        small_piecewise.set_over(.., 5.0);
+        small_piecewise.clear_over(200..230);
+
+        assert_eq!(small_piecewise.value_at(1), Some(&5.0));
+        assert_eq!(small_piecewise.value_at(210), None);
+        assert_eq!(small_piecewise.value_at(230), Some(&5.0));
+    }
+
+    #[test]
+    fn small_piecewise_segments_iterates_stored_segments() {
+        let mut small_piecewise: SmallPiecewise<u32, f32> = SmallPiecewise::default();
+        small_piecewise.set_over(..200, 1.0);
+        small_piecewise.set_over(230.., 2.0);
+
+        let values: Vec<_> = small_piecewise.segments().map(|voi| *voi.value()).collect();
+        assert_eq!(values.len(), 2);
+        assert!(values.contains(&1.0));
+        assert!(values.contains(&2.0));
+    }
+
+    #[test]
+    fn small_piecewise_gaps_yields_undefined_regions() {
+        let mut small_piecewise: SmallPiecewise<u32, f32> = SmallPiecewise::default();
+        small_piecewise.set_over(..200, 1.0);
+        small_piecewise.set_over(230.., 2.0);
+
+        let gaps: Vec<_> = small_piecewise.gaps().collect();
+        assert_eq!(
+            gaps,
+            vec![Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(200, 230).unwrap()
+            }]
+        );
+    }
+
+    #[test]
+    fn small_piecewise_gaps_empty_when_fully_defined() {
+        let mut small_piecewise: SmallPiecewise<u32, f32> = SmallPiecewise::default();
+        small_piecewise.set_over(.., 1.0);
+
+        assert_eq!(small_piecewise.gaps().count(), 0);
+    }
 }